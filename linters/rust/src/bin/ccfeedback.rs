@@ -0,0 +1,110 @@
+//! CLI entry point: lints Rust files and, with `--fix`, rewrites them in
+//! place using the autofixes attached to each finding.
+
+use std::path::Path;
+use std::{fs, process};
+
+use ccfeedback_rust::dead_code::DeadCodeRule;
+use ccfeedback_rust::must_use::MustUseRule;
+use ccfeedback_rust::output::format_findings;
+use ccfeedback_rust::rules::{check_all, default_rules};
+use ccfeedback_rust::{apply_policy, exit_code, fix_source, Config, OutputFormat};
+
+const USAGE: &str = "usage: ccfeedback [--fix] [--format=human|sarif] <file>...";
+
+fn main() {
+    let mut fix_mode = false;
+    let mut format = OutputFormat::Human;
+    let mut paths = Vec::new();
+
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--fix" => fix_mode = true,
+            "--format=sarif" => format = OutputFormat::Sarif,
+            "--format=human" => format = OutputFormat::Human,
+            path => paths.push(path.to_string()),
+        }
+    }
+
+    if paths.is_empty() {
+        eprintln!("{USAGE}");
+        process::exit(2);
+    }
+
+    let config = load_config();
+    let mut rules = default_rules();
+    // These two are whole-file analyses rather than mechanical style
+    // checks, so they're layered on top of `default_rules()` here instead
+    // of joining its curated, individually-fixable set.
+    rules.push(Box::new(DeadCodeRule));
+    rules.push(Box::new(MustUseRule));
+    let mut worst_exit_code = 0;
+
+    for path in &paths {
+        worst_exit_code = worst_exit_code.max(lint_file(path, &rules, &config, fix_mode, format));
+    }
+
+    process::exit(worst_exit_code);
+}
+
+/// Loads `ccfeedback.toml` from the current directory, falling back to the
+/// default policy (every rule at `warn`) when it isn't present.
+fn load_config() -> Config {
+    match fs::read_to_string("ccfeedback.toml") {
+        Ok(text) => Config::parse(&text).unwrap_or_else(|err| {
+            eprintln!("ccfeedback.toml: {err}");
+            process::exit(2);
+        }),
+        Err(_) => Config::default(),
+    }
+}
+
+/// Lints a single file, optionally rewriting it in place, and returns the
+/// exit code its findings imply.
+fn lint_file(
+    path: &str,
+    rules: &[Box<dyn ccfeedback_rust::LintRule>],
+    config: &Config,
+    fix_mode: bool,
+    format: OutputFormat,
+) -> i32 {
+    let source = match fs::read_to_string(path) {
+        Ok(source) => source,
+        Err(err) => {
+            eprintln!("{path}: {err}");
+            return 2;
+        }
+    };
+
+    let ast = match syn::parse_file(&source) {
+        Ok(ast) => ast,
+        Err(err) => {
+            eprintln!("{path}: {err}");
+            return 2;
+        }
+    };
+
+    let findings = apply_policy(check_all(rules, &ast, &source), config, &source);
+
+    if fix_mode {
+        match fix_source(&source, &findings) {
+            Ok(fixed) if fixed != source => {
+                if let Err(err) = fs::write(Path::new(path), fixed) {
+                    eprintln!("{path}: {err}");
+                    return 2;
+                }
+            }
+            Ok(_) => {}
+            Err(conflict) => {
+                eprintln!("{path}: overlapping fixes, skipping ({conflict:?})");
+            }
+        }
+    } else {
+        let rendered = format_findings(path, &findings, format);
+        if !rendered.is_empty() {
+            println!("{rendered}");
+        }
+    }
+
+    exit_code(&findings)
+}
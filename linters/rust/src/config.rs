@@ -0,0 +1,186 @@
+//! Configurable rule severity and allow/deny policy.
+//!
+//! Mirrors how `rustc`/clippy let users set lints to warn vs deny and
+//! allow individual ones: each built-in rule maps to `allow`/`warn`/`deny`
+//! in a `ccfeedback.toml`, with a baseline `level` for rules the config
+//! doesn't mention. The same idea is also available inline as
+//! `// ccfeedback:allow(rule_id)`, so a single file can opt out without
+//! touching the project-wide config.
+
+use std::collections::{HashMap, HashSet};
+
+use serde::Deserialize;
+
+use crate::{Finding, Severity};
+
+/// How strictly a rule should be enforced.
+///
+/// `"all"` is accepted as an alias for `warn`: it's meant for the baseline
+/// `level`, to mean "warn on everything not otherwise configured", and
+/// reads more clearly there than `warn` does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Policy {
+    Allow,
+    #[serde(alias = "all")]
+    Warn,
+    Deny,
+}
+
+impl Policy {
+    fn severity(self) -> Option<Severity> {
+        match self {
+            Policy::Allow => None,
+            Policy::Warn => Some(Severity::Warn),
+            Policy::Deny => Some(Severity::Deny),
+        }
+    }
+
+    fn default_level() -> Policy {
+        Policy::Warn
+    }
+}
+
+/// A project's rule policy, typically loaded from `ccfeedback.toml`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Config {
+    /// The policy for any rule not listed in `rules`.
+    #[serde(default = "Policy::default_level")]
+    pub level: Policy,
+    /// Per-rule overrides, keyed by rule id (e.g. `"wildcard_import"`).
+    #[serde(default)]
+    pub rules: HashMap<String, Policy>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            level: Policy::default_level(),
+            rules: HashMap::new(),
+        }
+    }
+}
+
+impl Config {
+    /// Parses a `ccfeedback.toml` document.
+    pub fn parse(toml_source: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(toml_source)
+    }
+
+    fn policy_for(&self, rule_id: &str) -> Policy {
+        self.rules.get(rule_id).copied().unwrap_or(self.level)
+    }
+}
+
+/// Rule ids suppressed for a whole file via an inline
+/// `// ccfeedback:allow(rule_id)` comment.
+fn inline_allowed(source: &str) -> HashSet<String> {
+    let mut allowed = HashSet::new();
+    for line in source.lines() {
+        let Some(start) = line.find("ccfeedback:allow(") else {
+            continue;
+        };
+        let rest = &line[start + "ccfeedback:allow(".len()..];
+        if let Some(end) = rest.find(')') {
+            allowed.insert(rest[..end].trim().to_string());
+        }
+    }
+    allowed
+}
+
+/// Applies `config` and any inline `// ccfeedback:allow(...)` comments in
+/// `source` to `findings`: drops allowed findings and rewrites the
+/// severity of the rest to match the configured policy.
+pub fn apply_policy(findings: Vec<Finding>, config: &Config, source: &str) -> Vec<Finding> {
+    let inline_allowed = inline_allowed(source);
+    findings
+        .into_iter()
+        .filter(|finding| !inline_allowed.contains(finding.rule_id))
+        .filter_map(|mut finding| {
+            let severity = config.policy_for(finding.rule_id).severity()?;
+            finding.severity = severity;
+            Some(finding)
+        })
+        .collect()
+}
+
+/// The process exit code to use for `findings`: non-zero if any `deny`
+/// level finding fired.
+pub fn exit_code(findings: &[Finding]) -> i32 {
+    if findings.iter().any(|f| f.severity == Severity::Deny) {
+        1
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Location;
+
+    fn finding(rule_id: &'static str) -> Finding {
+        Finding {
+            rule_id,
+            message: "test finding".to_string(),
+            severity: Severity::Warn,
+            start: Location { line: 1, column: 1 },
+            end: Location { line: 1, column: 1 },
+            fix: None,
+        }
+    }
+
+    #[test]
+    fn default_config_keeps_warnings_at_warn() {
+        let config = Config::default();
+        let findings = apply_policy(vec![finding("wildcard_import")], &config, "");
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Warn);
+    }
+
+    #[test]
+    fn level_all_is_an_alias_for_warn() {
+        let config = Config::parse(r#"level = "all""#).unwrap();
+        let findings = apply_policy(vec![finding("wildcard_import")], &config, "");
+        assert_eq!(findings[0].severity, Severity::Warn);
+    }
+
+    #[test]
+    fn per_rule_override_escalates_to_deny() {
+        let config = Config::parse(
+            r#"
+            level = "warn"
+
+            [rules]
+            wildcard_import = "deny"
+            "#,
+        )
+        .unwrap();
+        let findings = apply_policy(vec![finding("wildcard_import")], &config, "");
+        assert_eq!(findings[0].severity, Severity::Deny);
+        assert_eq!(exit_code(&findings), 1);
+    }
+
+    #[test]
+    fn per_rule_override_allows_a_rule() {
+        let config = Config::parse(
+            r#"
+            level = "deny"
+
+            [rules]
+            wildcard_import = "allow"
+            "#,
+        )
+        .unwrap();
+        let findings = apply_policy(vec![finding("wildcard_import")], &config, "");
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn inline_allow_comment_suppresses_rule_for_the_whole_file() {
+        let config = Config::default();
+        let source = "// ccfeedback:allow(wildcard_import)\nuse std::io::*;\n";
+        let findings = apply_policy(vec![finding("wildcard_import")], &config, source);
+        assert!(findings.is_empty());
+    }
+}
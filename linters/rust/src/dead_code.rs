@@ -0,0 +1,275 @@
+//! Test-aware dead-code analysis.
+//!
+//! A plain "is this function called anywhere" pass either misses functions
+//! that are only exercised from `#[cfg(test)]` modules or, worse, flags
+//! them as dead when they're legitimately test-only helpers. This module
+//! builds a call graph across the file, tracks which call sites live
+//! inside a `#[cfg(test)]` module, and classifies each free function
+//! accordingly so the caller can phrase feedback precisely.
+
+use std::collections::{HashMap, HashSet};
+
+use proc_macro2::{Delimiter, Span, TokenStream, TokenTree};
+use syn::visit::{self, Visit};
+use syn::{ExprCall, ExprPath, Ident, ItemFn, ItemMod, Macro};
+
+use crate::rules::{Finding, LintRule, Severity};
+
+/// How a free function is used elsewhere in the file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Usage {
+    /// Never called from anywhere in the file.
+    Unused,
+    /// Only called from inside a `#[cfg(test)]` module.
+    TestOnly,
+    /// Called from at least one non-test call site.
+    Used,
+}
+
+/// A function's dead-code classification, with enough provenance for
+/// downstream formatting to phrase the feedback correctly.
+#[derive(Debug, Clone)]
+pub struct DeadCodeFinding {
+    pub name: String,
+    pub usage: Usage,
+    pub span: Span,
+}
+
+impl DeadCodeFinding {
+    /// A human-readable note matching the function's usage.
+    pub fn note(&self) -> String {
+        match self.usage {
+            Usage::Unused => format!("function `{}` is never used", self.name),
+            Usage::TestOnly => format!(
+                "function `{}` is only used by tests; consider `#[cfg(test)]` or making it `pub`",
+                self.name
+            ),
+            Usage::Used => format!("function `{}` is used", self.name),
+        }
+    }
+
+    /// Converts this analysis result into a lint [`Finding`].
+    fn into_finding(self) -> Finding {
+        Finding {
+            rule_id: "dead_code",
+            message: self.note(),
+            severity: Severity::Warn,
+            start: self.span.start().into(),
+            end: self.span.end().into(),
+            fix: None,
+        }
+    }
+}
+
+/// Adapts [`analyze`] into the CLI's finding pipeline, surfacing every
+/// function that isn't `Usage::Used`.
+pub struct DeadCodeRule;
+
+impl LintRule for DeadCodeRule {
+    fn id(&self) -> &'static str {
+        "dead_code"
+    }
+
+    fn check(&self, ast: &syn::File, _source: &str) -> Vec<Finding> {
+        analyze(ast)
+            .into_iter()
+            .filter(|finding| finding.usage != Usage::Used)
+            .map(DeadCodeFinding::into_finding)
+            .collect()
+    }
+}
+
+/// Walks `ast`, classifying every free function by how (if at all) it's
+/// called elsewhere in the file. Only `Unused` and `TestOnly` functions are
+/// generally worth surfacing; `Used` is included so callers can filter.
+///
+/// `main` and `#[test]` functions are entry points rather than library
+/// code: nothing in the file is expected to call them, so they're left out
+/// of the report entirely instead of being misreported as `Unused`.
+pub fn analyze(ast: &syn::File) -> Vec<DeadCodeFinding> {
+    let mut collector = Collector::default();
+    collector.visit_file(ast);
+
+    collector
+        .declared
+        .iter()
+        .filter(|name| *name != "main" && !collector.entry_points.contains(*name))
+        .map(|name| {
+            let calls_outside_tests = collector.calls_outside_tests.contains(name);
+            let calls_inside_tests = collector.calls_inside_tests.contains(name);
+            let usage = if calls_outside_tests {
+                Usage::Used
+            } else if calls_inside_tests {
+                Usage::TestOnly
+            } else {
+                Usage::Unused
+            };
+            DeadCodeFinding {
+                name: name.clone(),
+                usage,
+                span: collector.spans[name],
+            }
+        })
+        .collect()
+}
+
+#[derive(Default)]
+struct Collector {
+    /// Free functions declared at any nesting level.
+    declared: HashSet<String>,
+    /// Each declared function's span, for reporting a location.
+    spans: HashMap<String, Span>,
+    /// Functions annotated `#[test]`; these are entry points, not callees.
+    entry_points: HashSet<String>,
+    /// Names called from inside a `#[cfg(test)]` module.
+    calls_inside_tests: HashSet<String>,
+    /// Names called from outside any `#[cfg(test)]` module.
+    calls_outside_tests: HashSet<String>,
+    in_test_module: bool,
+}
+
+impl Collector {
+    fn record_call(&mut self, name: String) {
+        if self.in_test_module {
+            self.calls_inside_tests.insert(name);
+        } else {
+            self.calls_outside_tests.insert(name);
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for Collector {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        let name = node.sig.ident.to_string();
+        self.spans.insert(name.clone(), node.sig.ident.span());
+        self.declared.insert(name.clone());
+        if has_test_attr(&node.attrs) {
+            self.entry_points.insert(name);
+        }
+        visit::visit_item_fn(self, node);
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        let was_in_test_module = self.in_test_module;
+        if is_cfg_test(node) {
+            self.in_test_module = true;
+        }
+        visit::visit_item_mod(self, node);
+        self.in_test_module = was_in_test_module;
+    }
+
+    fn visit_expr_call(&mut self, node: &'ast ExprCall) {
+        if let syn::Expr::Path(ExprPath { path, .. }) = &*node.func {
+            if let Some(name) = path.get_ident().map(Ident::to_string) {
+                self.record_call(name);
+            }
+        }
+        visit::visit_expr_call(self, node);
+    }
+
+    // `syn` treats a macro invocation's body as an opaque `TokenStream`, so
+    // a call like `assert_eq!(helper(), 1)` is invisible to `visit_expr_call`.
+    // Scan the raw tokens for `ident (...)` shapes instead.
+    fn visit_macro(&mut self, node: &'ast Macro) {
+        let mut calls = Vec::new();
+        collect_call_like_idents(node.tokens.clone(), &mut calls);
+        for name in calls {
+            self.record_call(name);
+        }
+        visit::visit_macro(self, node);
+    }
+}
+
+/// Recursively scans `tokens` for `ident (...)` shapes, the token pattern a
+/// function call leaves behind regardless of which macro it's nested in.
+fn collect_call_like_idents(tokens: TokenStream, out: &mut Vec<String>) {
+    let mut iter = tokens.into_iter().peekable();
+    while let Some(tt) = iter.next() {
+        match tt {
+            TokenTree::Ident(ident) => {
+                if let Some(TokenTree::Group(group)) = iter.peek() {
+                    if group.delimiter() == Delimiter::Parenthesis {
+                        out.push(ident.to_string());
+                    }
+                }
+            }
+            TokenTree::Group(group) => collect_call_like_idents(group.stream(), out),
+            _ => {}
+        }
+    }
+}
+
+fn has_test_attr(attrs: &[syn::Attribute]) -> bool {
+    attrs.iter().any(|attr| attr.path().is_ident("test"))
+}
+
+fn is_cfg_test(node: &ItemMod) -> bool {
+    node.attrs.iter().any(|attr| {
+        attr.path().is_ident("cfg")
+            && attr
+                .parse_args::<syn::Meta>()
+                .map(|meta| meta.path().is_ident("test"))
+                .unwrap_or(false)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(src: &str) -> syn::File {
+        syn::parse_file(src).expect("fixture should parse as valid Rust")
+    }
+
+    #[test]
+    fn flags_unused_function_in_warnings_fixture() {
+        let ast = parse(include_str!("../testdata/lint_warnings.rs"));
+        let findings = analyze(&ast);
+        let unused_function = findings
+            .iter()
+            .find(|f| f.name == "unused_function")
+            .expect("unused_function should be classified");
+        assert_eq!(unused_function.usage, Usage::Unused);
+    }
+
+    #[test]
+    fn classifies_test_only_helper_distinctly() {
+        let ast = parse(
+            r#"
+            fn helper() -> i32 { 1 }
+
+            #[cfg(test)]
+            mod tests {
+                use super::*;
+
+                #[test]
+                fn it_works() {
+                    assert_eq!(helper(), 1);
+                }
+            }
+            "#,
+        );
+        let findings = analyze(&ast);
+        let helper = findings
+            .iter()
+            .find(|f| f.name == "helper")
+            .expect("helper should be classified");
+        assert_eq!(helper.usage, Usage::TestOnly);
+        assert!(helper.note().contains("#[cfg(test)]"));
+    }
+
+    #[test]
+    fn entry_points_are_not_reported() {
+        let ast = parse(include_str!("../testdata/valid.rs"));
+        let findings = analyze(&ast);
+        assert!(!findings.iter().any(|f| f.name == "main"));
+        assert!(!findings.iter().any(|f| f.name == "test_person_creation"));
+    }
+
+    #[test]
+    fn valid_fixture_has_no_unused_functions() {
+        let ast = parse(include_str!("../testdata/valid.rs"));
+        let findings = analyze(&ast);
+        assert!(findings.iter().all(|f| f.usage != Usage::Unused));
+    }
+}
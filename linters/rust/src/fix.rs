@@ -0,0 +1,121 @@
+//! Machine-applicable autofixes.
+//!
+//! Every lint rule that has a well-known mechanical fix (`s == ""` ->
+//! `s.is_empty()`, `return a + b;` -> `a + b`, `&String` -> `&str`, ...)
+//! attaches a [`Fix`] to its [`Finding`](crate::Finding). [`apply_fixes`]
+//! then performs the underlying byte-range substitutions.
+
+use std::ops::Range;
+
+use proc_macro2::Span;
+
+use crate::Finding;
+
+/// A single mechanical, non-overlapping source rewrite.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fix {
+    /// Byte range in the original source to replace.
+    pub span: Range<usize>,
+    /// Text to substitute in place of `span`.
+    pub replacement: String,
+}
+
+/// Two fixes whose spans overlap; only one of them can be applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixConflict {
+    pub first: Fix,
+    pub second: Fix,
+}
+
+/// Converts a `syn`/`proc-macro2` span into the byte range [`Fix`] expects.
+pub fn span_range(span: Span) -> Range<usize> {
+    span.byte_range()
+}
+
+/// Applies `fixes` to `source`, substituting each fix's byte range with its
+/// replacement text. Fixes are applied in reverse span order so earlier
+/// byte offsets stay valid as later edits are made.
+///
+/// Returns the first pair of overlapping fixes as an error rather than
+/// silently applying one and dropping the other.
+pub fn apply_fixes(source: &str, fixes: &[Fix]) -> Result<String, FixConflict> {
+    let mut sorted: Vec<&Fix> = fixes.iter().collect();
+    sorted.sort_by_key(|fix| fix.span.start);
+
+    for pair in sorted.windows(2) {
+        let (a, b) = (pair[0], pair[1]);
+        if a.span.end > b.span.start {
+            return Err(FixConflict {
+                first: a.clone(),
+                second: b.clone(),
+            });
+        }
+    }
+
+    let mut result = source.to_string();
+    for fix in sorted.into_iter().rev() {
+        result.replace_range(fix.span.clone(), &fix.replacement);
+    }
+    Ok(result)
+}
+
+/// Collects the [`Fix`]es attached to `findings` and applies them to
+/// `source`. This is what a `--fix` CLI mode calls to rewrite a file in
+/// place.
+pub fn fix_source(source: &str, findings: &[Finding]) -> Result<String, FixConflict> {
+    let fixes: Vec<Fix> = findings.iter().filter_map(|f| f.fix.clone()).collect();
+    apply_fixes(source, &fixes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn applies_non_overlapping_fixes_in_reverse_order() {
+        let source = "abcdef".to_string();
+        let fixes = vec![
+            Fix {
+                span: 0..1,
+                replacement: "X".into(),
+            },
+            Fix {
+                span: 4..6,
+                replacement: "Y".into(),
+            },
+        ];
+        assert_eq!(apply_fixes(&source, &fixes).unwrap(), "XbcdY");
+    }
+
+    #[test]
+    fn detects_overlapping_fixes() {
+        let source = "abcdef".to_string();
+        let fixes = vec![
+            Fix {
+                span: 0..3,
+                replacement: "X".into(),
+            },
+            Fix {
+                span: 2..4,
+                replacement: "Y".into(),
+            },
+        ];
+        assert!(apply_fixes(&source, &fixes).is_err());
+    }
+
+    #[test]
+    fn fixes_round_trip_on_warnings_fixture() {
+        use crate::rules::{check_all, default_rules};
+
+        let source = include_str!("../testdata/lint_warnings.rs");
+        let ast = syn::parse_file(source).expect("fixture should parse as valid Rust");
+        let findings = check_all(&default_rules(), &ast, source);
+
+        let fixed = fix_source(source, &findings).expect("fixture fixes should not conflict");
+        assert!(!fixed.contains("use std::io::*;"));
+        assert!(!fixed.contains("s.clone().clone()"));
+        assert!(!fixed.contains("return a + b;"));
+        assert!(!fixed.contains("== \"\""));
+        assert!(!fixed.contains("&String"));
+    }
+}
@@ -0,0 +1,17 @@
+//! Rust-specific linting for ccfeedback.
+//!
+//! This crate inspects a parsed `syn::File` directly and reports common
+//! Clippy-style issues without shelling out to an external toolchain, so
+//! feedback stays fast and available even when `clippy` isn't installed.
+
+pub mod config;
+pub mod dead_code;
+pub mod fix;
+pub mod must_use;
+pub mod output;
+pub mod rules;
+
+pub use config::{apply_policy, exit_code, Config, Policy};
+pub use fix::{apply_fixes, fix_source, Fix};
+pub use output::OutputFormat;
+pub use rules::{Finding, LintRule, Location, Severity};
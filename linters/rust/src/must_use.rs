@@ -0,0 +1,373 @@
+//! `#[must_use]` propagation analysis.
+//!
+//! Mirrors the standard library's guidance to annotate side-effect-free
+//! functions with `#[must_use]`: this pass records every function's
+//! attributes and return type during the AST walk, then matches
+//! statement-level expression drops against that table so a dropped
+//! `Result`/`Option` (or an explicitly `#[must_use]` value) is reported
+//! at the call site, while it separately suggests adding `#[must_use]`
+//! to functions that look like pure accessors.
+//!
+//! Free functions are keyed by their bare name; methods are keyed as
+//! `Type::method` so that, say, `Person::greet` and some unrelated
+//! `Widget::greet` don't collide in the table. Associated-function calls
+//! (`Person::new()`) resolve to that qualified key directly; plain method
+//! calls (`person.greet()`) don't carry enough type information to qualify
+//! without full type inference, so they're matched only when the method
+//! name is unique across every type recorded in this file.
+
+use std::collections::HashMap;
+
+use proc_macro2::Span;
+use syn::spanned::Spanned;
+use syn::visit::{self, Visit};
+use syn::{
+    Expr, ExprCall, ExprMethodCall, FnArg, ImplItemFn, ItemFn, ItemImpl, ReturnType, Signature,
+    Stmt, Type,
+};
+
+use crate::rules::{Finding, LintRule, Severity};
+
+/// A single finding from the must-use pass.
+#[derive(Debug, Clone)]
+pub enum MustUseFinding {
+    /// A call site dropped a value that should have been used.
+    IgnoredResult {
+        name: String,
+        reason: String,
+        span: Span,
+    },
+    /// A user-defined function looks like a pure accessor and would
+    /// benefit from `#[must_use]`.
+    SuggestAnnotation { name: String, span: Span },
+}
+
+impl MustUseFinding {
+    /// Converts this analysis result into a lint [`Finding`].
+    fn into_finding(self) -> Finding {
+        let (message, span) = match &self {
+            MustUseFinding::IgnoredResult { name, reason, span } => {
+                (format!("ignored result of `{name}` ({reason})"), *span)
+            }
+            MustUseFinding::SuggestAnnotation { name, span } => (
+                format!("`{name}` looks like a pure accessor; consider `#[must_use]`"),
+                *span,
+            ),
+        };
+        Finding {
+            rule_id: "must_use",
+            message,
+            severity: Severity::Warn,
+            start: span.start().into(),
+            end: span.end().into(),
+            fix: None,
+        }
+    }
+}
+
+/// Adapts [`analyze`] into the CLI's finding pipeline.
+pub struct MustUseRule;
+
+impl LintRule for MustUseRule {
+    fn id(&self) -> &'static str {
+        "must_use"
+    }
+
+    fn check(&self, ast: &syn::File, _source: &str) -> Vec<Finding> {
+        analyze(ast)
+            .into_iter()
+            .map(MustUseFinding::into_finding)
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct FunctionInfo {
+    has_must_use: bool,
+    returns_result_or_option: bool,
+    is_pure_accessor: bool,
+    def_span: Span,
+}
+
+/// Runs the must-use pass over `ast`.
+pub fn analyze(ast: &syn::File) -> Vec<MustUseFinding> {
+    let mut table_builder = TableBuilder::default();
+    table_builder.visit_file(ast);
+    let table = table_builder.table;
+
+    let mut findings: Vec<MustUseFinding> = table
+        .iter()
+        .filter(|(_, info)| !info.has_must_use && info.is_pure_accessor)
+        .map(|(name, info)| MustUseFinding::SuggestAnnotation {
+            name: name.clone(),
+            span: info.def_span,
+        })
+        .collect();
+
+    let mut dropped = DroppedResultVisitor {
+        table: &table,
+        findings: Vec::new(),
+    };
+    dropped.visit_file(ast);
+    findings.extend(dropped.findings);
+    findings
+}
+
+#[derive(Default)]
+struct TableBuilder {
+    table: HashMap<String, FunctionInfo>,
+    current_type: Option<String>,
+    in_trait_impl: bool,
+}
+
+impl TableBuilder {
+    fn record(&mut self, key: String, sig: &Signature, attrs: &[syn::Attribute]) {
+        let info = FunctionInfo {
+            has_must_use: has_must_use_attr(attrs),
+            returns_result_or_option: returns_result_or_option(&sig.output),
+            // Trait impl methods (e.g. `Display::fmt`) implement someone
+            // else's contract; whether they should be `#[must_use]` isn't
+            // this file's call to make, so they're never suggested.
+            is_pure_accessor: !self.in_trait_impl && is_pure_accessor(sig),
+            def_span: sig.ident.span(),
+        };
+        self.table.insert(key, info);
+    }
+}
+
+impl<'ast> Visit<'ast> for TableBuilder {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        self.record(node.sig.ident.to_string(), &node.sig, &node.attrs);
+        visit::visit_item_fn(self, node);
+    }
+
+    fn visit_item_impl(&mut self, node: &'ast ItemImpl) {
+        let previous_type = self.current_type.take();
+        let previous_in_trait_impl = self.in_trait_impl;
+        self.current_type = type_name(&node.self_ty);
+        self.in_trait_impl = node.trait_.is_some();
+        visit::visit_item_impl(self, node);
+        self.current_type = previous_type;
+        self.in_trait_impl = previous_in_trait_impl;
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        let key = qualify(self.current_type.as_deref(), &node.sig.ident.to_string());
+        self.record(key, &node.sig, &node.attrs);
+        visit::visit_impl_item_fn(self, node);
+    }
+}
+
+struct DroppedResultVisitor<'t> {
+    table: &'t HashMap<String, FunctionInfo>,
+    findings: Vec<MustUseFinding>,
+}
+
+impl<'t, 'ast> Visit<'ast> for DroppedResultVisitor<'t> {
+    fn visit_block(&mut self, node: &'ast syn::Block) {
+        for stmt in &node.stmts {
+            if let Stmt::Expr(expr, Some(_semi)) = stmt {
+                self.check_dropped(expr);
+            }
+        }
+        visit::visit_block(self, node);
+    }
+}
+
+impl<'t> DroppedResultVisitor<'t> {
+    fn check_dropped(&mut self, expr: &Expr) {
+        let Some((name, info)) = resolve_callee(self.table, expr) else {
+            return;
+        };
+        let reason = if info.has_must_use {
+            Some("marked `#[must_use]`")
+        } else if info.returns_result_or_option {
+            Some("returns a `Result`/`Option` that may carry an error or absence")
+        } else {
+            None
+        };
+        if let Some(reason) = reason {
+            self.findings.push(MustUseFinding::IgnoredResult {
+                name,
+                reason: reason.to_string(),
+                span: expr.span(),
+            });
+        }
+    }
+}
+
+/// Resolves a call expression to its entry in `table`, if any.
+fn resolve_callee<'t>(
+    table: &'t HashMap<String, FunctionInfo>,
+    expr: &Expr,
+) -> Option<(String, &'t FunctionInfo)> {
+    match expr {
+        Expr::Call(ExprCall { func, .. }) => {
+            let Expr::Path(path) = &**func else {
+                return None;
+            };
+            let segments: Vec<String> = path
+                .path
+                .segments
+                .iter()
+                .map(|s| s.ident.to_string())
+                .collect();
+            match segments.as_slice() {
+                [name] => table.get(name).map(|info| (name.clone(), info)),
+                [.., ty, method] => {
+                    let key = qualify(Some(ty), method);
+                    table.get(&key).map(|info| (key.clone(), info))
+                }
+                [] => None,
+            }
+        }
+        Expr::MethodCall(ExprMethodCall { method, .. }) => {
+            // No type inference available, so only resolve the call when
+            // its method name is unambiguous across every type we saw.
+            let suffix = format!("::{method}");
+            let mut matches = table.iter().filter(|(key, _)| key.ends_with(&suffix));
+            let (key, info) = matches.next()?;
+            if matches.next().is_some() {
+                return None;
+            }
+            Some((key.clone(), info))
+        }
+        _ => None,
+    }
+}
+
+fn qualify(type_name: Option<&str>, method: &str) -> String {
+    match type_name {
+        Some(ty) => format!("{ty}::{method}"),
+        None => method.to_string(),
+    }
+}
+
+fn type_name(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Path(path) => path.path.segments.last().map(|s| s.ident.to_string()),
+        _ => None,
+    }
+}
+
+fn has_must_use_attr(attrs: &[syn::Attribute]) -> bool {
+    attrs.iter().any(|attr| attr.path().is_ident("must_use"))
+}
+
+fn returns_result_or_option(output: &ReturnType) -> bool {
+    let ReturnType::Type(_, ty) = output else {
+        return false;
+    };
+    let Type::Path(path) = &**ty else {
+        return false;
+    };
+    matches!(
+        path.path.segments.last().map(|s| s.ident.to_string()).as_deref(),
+        Some("Result") | Some("Option")
+    )
+}
+
+/// Heuristic for "pure accessor": a constructor (`new` returning a value)
+/// or a `&self` getter that returns something and doesn't take `&mut self`.
+/// Callers are expected to additionally exclude trait impl methods, which
+/// implement someone else's contract rather than standing alone.
+fn is_pure_accessor(sig: &Signature) -> bool {
+    if matches!(sig.output, ReturnType::Default) {
+        return false;
+    }
+    if sig.ident == "new" && !takes_self(sig) {
+        return true;
+    }
+    matches!(sig.inputs.first(), Some(FnArg::Receiver(r)) if r.reference.is_some() && r.mutability.is_none())
+}
+
+fn takes_self(sig: &Signature) -> bool {
+    matches!(sig.inputs.first(), Some(FnArg::Receiver(_)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(src: &str) -> syn::File {
+        syn::parse_file(src).expect("fixture should parse as valid Rust")
+    }
+
+    #[test]
+    fn calculate_result_is_used_and_stays_silent() {
+        let ast = parse(include_str!("../testdata/lint_warnings.rs"));
+        let findings = analyze(&ast);
+        assert!(!findings.iter().any(|f| matches!(
+            f,
+            MustUseFinding::IgnoredResult { name, .. } if name == "calculate"
+        )));
+    }
+
+    #[test]
+    fn trait_impl_method_is_never_suggested() {
+        // `impl Display for MyStruct { fn fmt(...) -> Result { ... } }` in
+        // the fixture takes `&self` and returns a non-unit type, which
+        // would otherwise look exactly like a pure accessor.
+        let ast = parse(include_str!("../testdata/lint_warnings.rs"));
+        let findings = analyze(&ast);
+        assert!(!findings.iter().any(|f| matches!(
+            f,
+            MustUseFinding::SuggestAnnotation { name, .. } if name.ends_with("fmt")
+        )));
+    }
+
+    #[test]
+    fn dropped_result_is_flagged() {
+        let ast = parse(
+            r#"
+            fn might_fail() -> Result<(), ()> { Ok(()) }
+
+            fn main() {
+                might_fail();
+            }
+            "#,
+        );
+        let findings = analyze(&ast);
+        assert!(findings.iter().any(|f| matches!(
+            f,
+            MustUseFinding::IgnoredResult { name, .. } if name == "might_fail"
+        )));
+    }
+
+    #[test]
+    fn dropped_qualified_constructor_call_is_flagged() {
+        let ast = parse(
+            r#"
+            struct Thing;
+
+            impl Thing {
+                #[must_use]
+                fn new() -> Self { Thing }
+            }
+
+            fn main() {
+                Thing::new();
+            }
+            "#,
+        );
+        let findings = analyze(&ast);
+        assert!(findings.iter().any(|f| matches!(
+            f,
+            MustUseFinding::IgnoredResult { name, .. } if name == "Thing::new"
+        )));
+    }
+
+    #[test]
+    fn constructor_suggests_must_use() {
+        let ast = parse(include_str!("../testdata/valid.rs"));
+        let findings = analyze(&ast);
+        let suggested: Vec<&str> = findings
+            .iter()
+            .filter_map(|f| match f {
+                MustUseFinding::SuggestAnnotation { name, .. } => Some(name.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert!(suggested.contains(&"Person::new"));
+    }
+}
@@ -0,0 +1,20 @@
+use crate::{Finding, Severity};
+
+/// Renders findings the way a compiler would: one line per finding,
+/// `path:line:column: severity: message [rule_id]`.
+pub fn format(path: &str, findings: &[Finding]) -> String {
+    findings
+        .iter()
+        .map(|finding| {
+            let severity = match finding.severity {
+                Severity::Warn => "warning",
+                Severity::Deny => "error",
+            };
+            format!(
+                "{path}:{}:{}: {severity}: {} [{}]",
+                finding.start.line, finding.start.column, finding.message, finding.rule_id
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,25 @@
+//! Renders [`Finding`]s for a consumer: a terminal, or a CI dashboard /
+//! editor that understands SARIF.
+
+mod human;
+mod sarif;
+
+use crate::Finding;
+
+/// The supported ways to render a set of findings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    /// Plain text meant for a terminal.
+    #[default]
+    Human,
+    /// [SARIF 2.1.0](https://sarifweb.azurewebsites.net/), for CI dashboards and editors.
+    Sarif,
+}
+
+/// Renders `findings` for `path` in the requested `format`.
+pub fn format_findings(path: &str, findings: &[Finding], format: OutputFormat) -> String {
+    match format {
+        OutputFormat::Human => human::format(path, findings),
+        OutputFormat::Sarif => sarif::format(path, findings),
+    }
+}
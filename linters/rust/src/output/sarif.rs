@@ -0,0 +1,89 @@
+use std::collections::BTreeMap;
+
+use serde_json::{json, Value};
+
+use crate::{Finding, Severity};
+
+/// Renders `findings` as a SARIF 2.1.0 log (a single run, single tool).
+pub fn format(path: &str, findings: &[Finding]) -> String {
+    let mut rules: BTreeMap<&str, Value> = BTreeMap::new();
+    let mut results = Vec::with_capacity(findings.len());
+
+    for finding in findings {
+        rules.entry(finding.rule_id).or_insert_with(|| {
+            json!({
+                "id": finding.rule_id,
+                "shortDescription": { "text": finding.message },
+                "defaultConfiguration": { "level": level(finding.severity) },
+            })
+        });
+
+        results.push(json!({
+            "ruleId": finding.rule_id,
+            "level": level(finding.severity),
+            "message": { "text": finding.message },
+            "locations": [{
+                "physicalLocation": {
+                    "artifactLocation": { "uri": path },
+                    "region": {
+                        "startLine": finding.start.line,
+                        "startColumn": finding.start.column,
+                        "endColumn": finding.end.column,
+                    },
+                },
+            }],
+        }));
+    }
+
+    let log = json!({
+        "$schema": "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/master/Schemata/sarif-schema-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "ccfeedback",
+                    "rules": rules.into_values().collect::<Vec<_>>(),
+                },
+            },
+            "results": results,
+        }],
+    });
+
+    serde_json::to_string_pretty(&log).expect("SARIF log is always valid JSON")
+}
+
+/// Maps our warn/deny split onto SARIF's level vocabulary, mirroring the
+/// clippy/rustc convention that most lints default to `warning` while a
+/// smaller set of serious ones (e.g. out-of-bounds-style issues) deny by
+/// default and map to `error`.
+fn level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Warn => "warning",
+        Severity::Deny => "error",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Location;
+
+    #[test]
+    fn emits_one_rule_and_one_result_per_finding() {
+        let findings = vec![Finding {
+            rule_id: "wildcard_import",
+            message: "wildcard import; consider importing items explicitly".to_string(),
+            severity: Severity::Warn,
+            start: Location { line: 3, column: 1 },
+            end: Location { line: 3, column: 20 },
+            fix: None,
+        }];
+
+        let log: Value = serde_json::from_str(&format("lint_warnings.rs", &findings)).unwrap();
+        let run = &log["runs"][0];
+        assert_eq!(run["tool"]["driver"]["rules"].as_array().unwrap().len(), 1);
+        assert_eq!(run["results"][0]["ruleId"], "wildcard_import");
+        assert_eq!(run["results"][0]["level"], "warning");
+        assert_eq!(run["results"][0]["locations"][0]["physicalLocation"]["region"]["startLine"], 3);
+    }
+}
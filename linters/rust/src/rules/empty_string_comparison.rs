@@ -0,0 +1,78 @@
+use syn::spanned::Spanned;
+use syn::visit::{self, Visit};
+use syn::{BinOp, ExprBinary, ExprLit, Lit};
+
+use super::{Finding, Severity};
+use crate::fix::{span_range, Fix};
+
+/// Flags `s == ""` and `s != ""` in favor of `s.is_empty()`.
+pub struct EmptyStringComparison;
+
+impl super::LintRule for EmptyStringComparison {
+    fn id(&self) -> &'static str {
+        "empty_string_comparison"
+    }
+
+    fn check(&self, ast: &syn::File, _source: &str) -> Vec<Finding> {
+        let mut visitor = Visitor::default();
+        visitor.visit_file(ast);
+        visitor.findings
+    }
+}
+
+#[derive(Default)]
+struct Visitor {
+    findings: Vec<Finding>,
+}
+
+impl<'ast> Visit<'ast> for Visitor {
+    fn visit_expr_binary(&mut self, node: &'ast ExprBinary) {
+        let op = match node.op {
+            BinOp::Eq(_) => "==",
+            BinOp::Ne(_) => "!=",
+            _ => {
+                visit::visit_expr_binary(self, node);
+                return;
+            }
+        };
+
+        let other_operand = if is_empty_str_literal(&node.left) {
+            Some(&node.right)
+        } else if is_empty_str_literal(&node.right) {
+            Some(&node.left)
+        } else {
+            None
+        };
+
+        if let Some(operand) = other_operand {
+            let operand_text = quote::quote!(#operand).to_string();
+            let replacement = match op {
+                "==" => format!("{operand_text}.is_empty()"),
+                _ => format!("!{operand_text}.is_empty()"),
+            };
+            self.findings.push(Finding {
+                rule_id: "empty_string_comparison",
+                message: format!("comparison to empty string with `{op}`; use `.is_empty()`"),
+                severity: Severity::Warn,
+                start: node.span().start().into(),
+                end: node.span().end().into(),
+                fix: Some(Fix {
+                    span: span_range(node.span()),
+                    replacement,
+                }),
+            });
+        }
+
+        visit::visit_expr_binary(self, node);
+    }
+}
+
+fn is_empty_str_literal(expr: &syn::Expr) -> bool {
+    matches!(
+        expr,
+        syn::Expr::Lit(ExprLit {
+            lit: Lit::Str(s),
+            ..
+        }) if s.value().is_empty()
+    )
+}
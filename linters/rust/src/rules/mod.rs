@@ -0,0 +1,123 @@
+//! Built-in lint rules and the registry that runs them.
+
+mod empty_string_comparison;
+mod needless_return;
+mod redundant_clone;
+mod string_ref_param;
+mod wildcard_import;
+
+pub use empty_string_comparison::EmptyStringComparison;
+pub use needless_return::NeedlessReturn;
+pub use redundant_clone::RedundantClone;
+pub use string_ref_param::StringRefParam;
+pub use wildcard_import::WildcardImport;
+
+use crate::fix::Fix;
+
+/// How seriously a finding should be treated, mirroring rustc/clippy's
+/// warn/deny split.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Warn,
+    Deny,
+}
+
+/// A 1-based line/column source location.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl From<proc_macro2::LineColumn> for Location {
+    fn from(lc: proc_macro2::LineColumn) -> Self {
+        // proc_macro2 columns are 0-based; report 1-based like line numbers.
+        Location {
+            line: lc.line,
+            column: lc.column + 1,
+        }
+    }
+}
+
+/// A single lint finding produced by a [`LintRule`].
+#[derive(Debug, Clone)]
+pub struct Finding {
+    pub rule_id: &'static str,
+    pub message: String,
+    pub severity: Severity,
+    pub start: Location,
+    pub end: Location,
+    /// A machine-applicable rewrite for this finding, if one exists.
+    pub fix: Option<Fix>,
+}
+
+/// A single, self-contained lint check over a parsed Rust file.
+///
+/// Implementations should be cheap to construct and free of file-specific
+/// state so the same instance can be reused across files.
+pub trait LintRule {
+    /// Stable identifier used in config and output (e.g. `"wildcard_import"`).
+    fn id(&self) -> &'static str;
+
+    /// Runs this rule over `ast`, returning zero or more findings.
+    ///
+    /// `source` is the original text `ast` was parsed from; rules that
+    /// produce a [`Fix`] may use it to copy exact source text into the
+    /// replacement or to widen the fix's byte range.
+    fn check(&self, ast: &syn::File, source: &str) -> Vec<Finding>;
+}
+
+/// The built-in rules, in a stable order.
+pub fn default_rules() -> Vec<Box<dyn LintRule>> {
+    vec![
+        Box::new(WildcardImport),
+        Box::new(EmptyStringComparison),
+        Box::new(NeedlessReturn),
+        Box::new(StringRefParam),
+        Box::new(RedundantClone),
+    ]
+}
+
+/// Runs every rule in `rules` over `ast` and collects their findings.
+pub fn check_all(rules: &[Box<dyn LintRule>], ast: &syn::File, source: &str) -> Vec<Finding> {
+    rules
+        .iter()
+        .flat_map(|rule| rule.check(ast, source))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(src: &str) -> syn::File {
+        syn::parse_file(src).expect("fixture should parse as valid Rust")
+    }
+
+    #[test]
+    fn warnings_fixture_trips_every_rule() {
+        let source = include_str!("../../testdata/lint_warnings.rs");
+        let ast = parse(source);
+        let rules = default_rules();
+        for rule in &rules {
+            let findings = rule.check(&ast, source);
+            assert!(
+                !findings.is_empty(),
+                "expected rule `{}` to fire on the warnings fixture",
+                rule.id()
+            );
+        }
+    }
+
+    #[test]
+    fn valid_fixture_is_clean() {
+        let source = include_str!("../../testdata/valid.rs");
+        let ast = parse(source);
+        let findings = check_all(&default_rules(), &ast, source);
+        assert!(
+            findings.is_empty(),
+            "valid fixture should not trip any rule, got: {:?}",
+            findings
+        );
+    }
+}
@@ -0,0 +1,94 @@
+use syn::spanned::Spanned;
+use syn::visit::{self, Visit};
+use syn::{
+    Block, Expr, ExprClosure, ExprReturn, ImplItemFn, ItemFn, Stmt, TraitItemFn,
+};
+
+use super::{Finding, Severity};
+use crate::fix::{span_range, Fix};
+
+/// Flags a trailing `return expr;` in a function or closure body that
+/// could just be the tail expression (`expr`).
+///
+/// Only the tail statement of a fn/closure *body* is considered: a
+/// `return` inside an arbitrary nested block (e.g. `let y = { return 3; };`)
+/// still exits the enclosing function, so rewriting it to a bare tail
+/// expression would change what the code does.
+pub struct NeedlessReturn;
+
+impl super::LintRule for NeedlessReturn {
+    fn id(&self) -> &'static str {
+        "needless_return"
+    }
+
+    fn check(&self, ast: &syn::File, source: &str) -> Vec<Finding> {
+        let mut visitor = Visitor {
+            source,
+            findings: Vec::new(),
+        };
+        visitor.visit_file(ast);
+        visitor.findings
+    }
+}
+
+struct Visitor<'s> {
+    source: &'s str,
+    findings: Vec<Finding>,
+}
+
+impl<'s, 'ast> Visit<'ast> for Visitor<'s> {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        self.check_tail_return(&node.block);
+        visit::visit_item_fn(self, node);
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        self.check_tail_return(&node.block);
+        visit::visit_impl_item_fn(self, node);
+    }
+
+    fn visit_trait_item_fn(&mut self, node: &'ast TraitItemFn) {
+        if let Some(block) = &node.default {
+            self.check_tail_return(block);
+        }
+        visit::visit_trait_item_fn(self, node);
+    }
+
+    fn visit_expr_closure(&mut self, node: &'ast ExprClosure) {
+        if let Expr::Block(expr_block) = &*node.body {
+            self.check_tail_return(&expr_block.block);
+        }
+        visit::visit_expr_closure(self, node);
+    }
+}
+
+impl<'s> Visitor<'s> {
+    fn check_tail_return(&mut self, block: &Block) {
+        let Some(Stmt::Expr(Expr::Return(ExprReturn { expr: Some(expr), .. }), Some(_))) =
+            block.stmts.last()
+        else {
+            return;
+        };
+        let last = block.stmts.last().unwrap();
+        // Slice the returned expression straight out of `source` rather
+        // than re-rendering it with `quote!`, which drops the original
+        // spacing (`add(a, b)` -> `add (a , b)`).
+        let replacement = self
+            .source
+            .get(span_range(expr.span()))
+            .expect("span should index valid UTF-8 boundaries in its own source")
+            .to_string();
+        self.findings.push(Finding {
+            rule_id: "needless_return",
+            message: "unneeded `return` statement; remove it and drop the trailing `;`"
+                .to_string(),
+            severity: Severity::Warn,
+            start: last.span().start().into(),
+            end: last.span().end().into(),
+            fix: Some(Fix {
+                span: span_range(last.span()),
+                replacement,
+            }),
+        });
+    }
+}
@@ -0,0 +1,61 @@
+use syn::spanned::Spanned;
+use syn::visit::{self, Visit};
+use syn::{Expr, ExprMethodCall};
+
+use super::{Finding, Severity};
+use crate::fix::{span_range, Fix};
+
+/// Flags `x.clone().clone()`, where the second `.clone()` is redundant.
+pub struct RedundantClone;
+
+impl super::LintRule for RedundantClone {
+    fn id(&self) -> &'static str {
+        "redundant_clone"
+    }
+
+    fn check(&self, ast: &syn::File, source: &str) -> Vec<Finding> {
+        let mut visitor = Visitor {
+            source,
+            findings: Vec::new(),
+        };
+        visitor.visit_file(ast);
+        visitor.findings
+    }
+}
+
+struct Visitor<'s> {
+    source: &'s str,
+    findings: Vec<Finding>,
+}
+
+impl<'s, 'ast> Visit<'ast> for Visitor<'s> {
+    fn visit_expr_method_call(&mut self, node: &'ast ExprMethodCall) {
+        if node.method == "clone" {
+            if let Expr::MethodCall(inner) = &*node.receiver {
+                if inner.method == "clone" {
+                    // Slice the inner call straight out of `source` rather
+                    // than re-rendering it with `quote!`, which drops the
+                    // original spacing (`s.clone()` -> `s . clone ()`).
+                    let replacement = self
+                        .source
+                        .get(span_range(inner.span()))
+                        .expect("span should index valid UTF-8 boundaries in its own source")
+                        .to_string();
+                    self.findings.push(Finding {
+                        rule_id: "redundant_clone",
+                        message: "chained `.clone().clone()`; the second call is a no-op"
+                            .to_string(),
+                        severity: Severity::Warn,
+                        start: node.span().start().into(),
+                        end: node.span().end().into(),
+                        fix: Some(Fix {
+                            span: span_range(node.span()),
+                            replacement,
+                        }),
+                    });
+                }
+            }
+        }
+        visit::visit_expr_method_call(self, node);
+    }
+}
@@ -0,0 +1,117 @@
+use syn::spanned::Spanned;
+use syn::visit::{self, Visit};
+use syn::{FnArg, GenericArgument, Pat, PathArguments, Signature, Type};
+
+use super::{Finding, Severity};
+use crate::fix::{span_range, Fix};
+
+/// Flags `&String` and `&Vec<T>` parameters in favor of `&str` / `&[T]`,
+/// which accept a strict superset of callers.
+pub struct StringRefParam;
+
+impl super::LintRule for StringRefParam {
+    fn id(&self) -> &'static str {
+        "string_ref_param"
+    }
+
+    fn check(&self, ast: &syn::File, source: &str) -> Vec<Finding> {
+        let mut visitor = Visitor {
+            source,
+            findings: Vec::new(),
+        };
+        visitor.visit_file(ast);
+        visitor.findings
+    }
+}
+
+struct Visitor<'s> {
+    source: &'s str,
+    findings: Vec<Finding>,
+}
+
+impl<'s, 'ast> Visit<'ast> for Visitor<'s> {
+    fn visit_signature(&mut self, node: &'ast Signature) {
+        for arg in &node.inputs {
+            let FnArg::Typed(pat_type) = arg else {
+                continue;
+            };
+            let Some(suggestion) = reference_kind(&pat_type.ty) else {
+                continue;
+            };
+            let name = match &*pat_type.pat {
+                Pat::Ident(ident) => ident.ident.to_string(),
+                _ => "parameter".to_string(),
+            };
+            let owned = self.slice(suggestion.owned.span());
+            let replacement = match suggestion.kind {
+                // `&[T]` with a literal `T` doesn't compile; slice the
+                // element type straight out of the source instead.
+                RefKind::String => "&str".to_string(),
+                RefKind::Vec(elem) => format!("&[{}]", self.slice(elem.span())),
+            };
+            self.findings.push(Finding {
+                rule_id: "string_ref_param",
+                message: format!(
+                    "`{name}` takes `&{owned}`; `{replacement}` accepts more callers"
+                ),
+                severity: Severity::Warn,
+                start: pat_type.span().start().into(),
+                end: pat_type.span().end().into(),
+                fix: Some(Fix {
+                    span: span_range(pat_type.ty.span()),
+                    replacement,
+                }),
+            });
+        }
+        visit::visit_signature(self, node);
+    }
+}
+
+impl<'s> Visitor<'s> {
+    /// Renders the exact source text `span` covers.
+    fn slice(&self, span: proc_macro2::Span) -> &'s str {
+        self.source
+            .get(span_range(span))
+            .expect("span should index valid UTF-8 boundaries in its own source")
+    }
+}
+
+struct RefSuggestion<'t> {
+    /// The owned type behind the reference (`String` or `Vec<T>`).
+    owned: &'t Type,
+    kind: RefKind<'t>,
+}
+
+enum RefKind<'t> {
+    String,
+    Vec(&'t Type),
+}
+
+/// Classifies `ty` as `&String` or `&Vec<T>`, if it's either.
+fn reference_kind(ty: &Type) -> Option<RefSuggestion<'_>> {
+    let Type::Reference(reference) = ty else {
+        return None;
+    };
+    if reference.mutability.is_some() {
+        return None;
+    }
+    let owned = &*reference.elem;
+    let Type::Path(path) = owned else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    let kind = match segment.ident.to_string().as_str() {
+        "String" => RefKind::String,
+        "Vec" => {
+            let PathArguments::AngleBracketed(args) = &segment.arguments else {
+                return None;
+            };
+            match args.args.first()? {
+                GenericArgument::Type(elem) => RefKind::Vec(elem),
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+    Some(RefSuggestion { owned, kind })
+}
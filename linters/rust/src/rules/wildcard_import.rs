@@ -0,0 +1,115 @@
+use std::ops::Range;
+
+use syn::spanned::Spanned;
+use syn::visit::{self, Visit};
+use syn::{ItemMod, ItemUse, UseTree};
+
+use super::{Finding, Severity};
+use crate::fix::{span_range, Fix};
+
+/// Flags `use` statements that end in a wildcard (`use std::io::*;`).
+///
+/// Wildcard imports pull an unpredictable set of names into scope, which
+/// makes it easy to accidentally shadow an existing identifier and harder
+/// to tell where a given name came from. `use super::*;` and glob imports
+/// inside a `#[cfg(test)]` module are idiomatic and exempted, the same way
+/// clippy's `wildcard_imports` lint exempts them.
+pub struct WildcardImport;
+
+impl super::LintRule for WildcardImport {
+    fn id(&self) -> &'static str {
+        "wildcard_import"
+    }
+
+    fn check(&self, ast: &syn::File, source: &str) -> Vec<Finding> {
+        let mut visitor = Visitor {
+            source,
+            findings: Vec::new(),
+            in_test_module: false,
+        };
+        visitor.visit_file(ast);
+        visitor.findings
+    }
+}
+
+struct Visitor<'s> {
+    source: &'s str,
+    findings: Vec<Finding>,
+    in_test_module: bool,
+}
+
+impl<'s, 'ast> Visit<'ast> for Visitor<'s> {
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        let was_in_test_module = self.in_test_module;
+        if is_cfg_test(node) {
+            self.in_test_module = true;
+        }
+        visit::visit_item_mod(self, node);
+        self.in_test_module = was_in_test_module;
+    }
+
+    fn visit_item_use(&mut self, node: &'ast ItemUse) {
+        if ends_in_glob(&node.tree) && !self.in_test_module && !is_super_glob(&node.tree) {
+            self.findings.push(Finding {
+                rule_id: "wildcard_import",
+                message: "wildcard import; consider importing items explicitly".to_string(),
+                severity: Severity::Warn,
+                start: node.span().start().into(),
+                end: node.span().end().into(),
+                fix: Some(Fix {
+                    span: extend_through_trailing_comment(self.source, span_range(node.span())),
+                    replacement: String::new(),
+                }),
+            });
+        }
+        visit::visit_item_use(self, node);
+    }
+}
+
+/// Extends `span` (covering just the `use ...;` tokens) to also swallow a
+/// trailing same-line comment and the newline after it, so removing the
+/// import doesn't leave a dangling comment and a blank line behind.
+fn extend_through_trailing_comment(source: &str, span: Range<usize>) -> Range<usize> {
+    let bytes = source.as_bytes();
+    let mut cursor = span.end;
+    while cursor < bytes.len() && (bytes[cursor] == b' ' || bytes[cursor] == b'\t') {
+        cursor += 1;
+    }
+    if source[cursor..].starts_with("//") {
+        cursor += source[cursor..].find('\n').unwrap_or(source.len() - cursor);
+    }
+    if bytes.get(cursor) == Some(&b'\r') && bytes.get(cursor + 1) == Some(&b'\n') {
+        cursor += 2;
+    } else if bytes.get(cursor) == Some(&b'\n') {
+        cursor += 1;
+    }
+    span.start..cursor
+}
+
+fn ends_in_glob(tree: &UseTree) -> bool {
+    match tree {
+        UseTree::Glob(_) => true,
+        UseTree::Path(path) => ends_in_glob(&path.tree),
+        UseTree::Group(group) => group.items.iter().any(ends_in_glob),
+        _ => false,
+    }
+}
+
+/// True for `use super::*;`, the idiomatic way to re-expose a parent
+/// module's items in full.
+fn is_super_glob(tree: &UseTree) -> bool {
+    matches!(
+        tree,
+        UseTree::Path(path) if path.ident == "super" && matches!(*path.tree, UseTree::Glob(_))
+    )
+}
+
+fn is_cfg_test(node: &ItemMod) -> bool {
+    node.attrs.iter().any(|attr| {
+        attr.path().is_ident("cfg")
+            && attr
+                .parse_args::<syn::Meta>()
+                .map(|meta| meta.path().is_ident("test"))
+                .unwrap_or(false)
+    })
+}